@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// Describes why a save file name failed to parse into a [`crate::save_info_struct::SaveNameInfo`].
+///
+/// Each variant carries the offending substring and its byte offset within
+/// the original input, so callers can point at exactly what went wrong
+/// without depending on nom's error types.
+///
+/// There is no "trailing input" variant: the grammar's suffix stage enforces
+/// end-of-input itself, so leftover input is always reported as
+/// `InvalidSuffix` rather than reachable as its own case. Likewise, version
+/// text that isn't numeric (e.g. `user1_abc.dat`) isn't rejected by the
+/// grammar at all -- it silently folds back into `tag` instead of reaching
+/// `MalformedVersion`, which this crate currently only raises for a
+/// syntactically valid version component that overflows `u64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveNameParseError {
+    /// Input opens an internal tag with `__` but never finds the closing `__`.
+    UnterminatedInternalTag { substring: String, offset: usize },
+    /// Input (after an optional internal tag) does not start with `user`.
+    MissingUserPrefix { substring: String, offset: usize },
+    /// The version tag following `_` overflowed `u64` in one of its components.
+    MalformedVersion { substring: String, offset: usize },
+    /// The `.dat`/`.bak<id>` suffix is missing or malformed.
+    InvalidSuffix { substring: String, offset: usize },
+}
+
+impl fmt::Display for SaveNameParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnterminatedInternalTag { substring, offset } => {
+                write!(f, "unterminated internal tag at byte {offset}: {substring:?}")
+            }
+            Self::MissingUserPrefix { substring, offset } => {
+                write!(f, "missing `user` prefix at byte {offset}: {substring:?}")
+            }
+            Self::MalformedVersion { substring, offset } => {
+                write!(f, "malformed version at byte {offset}: {substring:?}")
+            }
+            Self::InvalidSuffix { substring, offset } => {
+                write!(f, "invalid `.dat`/`.bak` suffix at byte {offset}: {substring:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveNameParseError {}
+
+/// Describes why a set of [`crate::save_info_struct::SaveNameInfo`] fields
+/// cannot round-trip through `Display`/`FromStr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveNameValidationError {
+    /// `tag` contains the literal `.dat` sequence. `try_new` rejects it even
+    /// though `parse` recovers such a tag (`user1.dat.dat` has tag `1.dat`).
+    TagContainsSuffixMarker { tag: String },
+    /// `internal_tag` contains `__`, which truncates it on re-parse.
+    InternalTagContainsDelimiter { internal_tag: String },
+    /// `backup_id` is not a run of ASCII digits (or empty), which the
+    /// suffix grammar cannot represent.
+    BackupIdNotNumeric { backup_id: String },
+}
+
+impl fmt::Display for SaveNameValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TagContainsSuffixMarker { tag } => {
+                write!(f, "tag {tag:?} contains the reserved `.dat` sequence")
+            }
+            Self::InternalTagContainsDelimiter { internal_tag } => {
+                write!(f, "internal tag {internal_tag:?} contains the reserved `__` delimiter")
+            }
+            Self::BackupIdNotNumeric { backup_id } => {
+                write!(f, "backup id {backup_id:?} is not a run of ASCII digits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveNameValidationError {}
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod parser;
+pub mod save_info_struct;
+pub mod version;
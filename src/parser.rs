@@ -7,16 +7,20 @@ use nom::sequence::preceded;
 use nom::combinator::{eof, opt, peek, recognize};
 use nom::{IResult, Parser, multi::many_till};
 
+use crate::error::SaveNameParseError;
 use crate::save_info_struct::SaveNameInfo;
+use crate::version::Version;
 
 /// Parses internal tag
 /// 
 /// The excepted internal tag pattern is a string, enclosed in two 
 /// "double underscores" (`__`) symbols.
 /// 
-/// ```rust
-/// assert_eq!(parser("__some_attr__"), Ok(("", "some_attr")));
-/// assert_eq!(parser("__sometag__user2.dat"), Ok(("user2.dat", "sometag")));
+/// ```rust,ignore
+/// // `parse_tag_internal` is private to this module, so this example is
+/// // illustrative only -- rustdoc can't compile it as an external-crate doctest.
+/// assert_eq!(parse_tag_internal("__some_attr__"), Ok(("", "some_attr")));
+/// assert_eq!(parse_tag_internal("__sometag__user2.dat"), Ok(("user2.dat", "sometag")));
 /// ```
 fn parse_tag_internal(input: &str) -> IResult<&str, &str> {
 
@@ -30,7 +34,9 @@ fn parse_tag_internal(input: &str) -> IResult<&str, &str> {
 
 /// Parse backup id from file suffix
 /// 
-/// ```rust, no-run
+/// ```rust,ignore
+/// // `parse_suffix_bak` is private to this module; see `test_parse_suffix`
+/// // for the executable version of this example.
 /// assert_eq!(parse_suffix_bak(".bak123"), Ok(("", "123")));
 /// assert_eq!(parse_suffix_bak(".bak"), Ok(("", "")));
 /// ```
@@ -45,7 +51,9 @@ fn parse_suffix_bak(input: &str) -> IResult<&str, &str> {
 
 /// Parses suffix and optional backup id
 /// 
-/// ```rust, no-run
+/// ```rust,ignore
+/// // `parse_suffix` is private to this module; see `test_parse_suffix`
+/// // for the executable version of this example.
 /// assert_eq!(parse_suffix(".dat"), Ok(("", None)));
 /// assert_eq!(parse_suffix(".dat.bak"), Ok(("", Some(""))));
 /// assert_eq!(parse_suffix(".dat.bak123"), Ok(("", Some("123"))));
@@ -60,7 +68,9 @@ fn parse_suffix(input: &str) -> IResult<&str, Option<&str>> {
 
 /// Parse the version tag
 /// 
-/// ```rust, no-run
+/// ```rust,ignore
+/// // `parse_version` is private to this module; this example is illustrative
+/// // only -- rustdoc can't compile it as an external-crate doctest.
 /// assert_eq!(parse_version("_1.0.28891"), Ok(("", "1.0.28891"))); // HKSS version
 /// assert_eq!(parse_version("_1.2.3.28891"), Ok(("", "1.2.3.28891"))); // legacy HKversion
 /// ```
@@ -76,7 +86,9 @@ fn parse_version(input: &str) -> IResult<&str, &str> {
 
 /// Parses user tag
 /// 
-/// ```rust, no-run
+/// ```rust,ignore
+/// // `parse_user_tag` is private to this module; see `test_parser_user_tag`
+/// // for the executable version of these examples.
 /// assert_eq!(parse_user_tag("user1.dat"), Ok((".dat", "1"))); // basic case.
 /// assert_eq!(parse_user_tag("user4_1.0.28891.dat"), Ok(("_1.0.28891.dat", "4"))); // with version
 /// assert_eq!(parse_user_tag("usera-b_c__d.e.dat"), Ok((".dat", "a-b_c__d.e"))); // different symbols
@@ -110,16 +122,115 @@ pub fn parse(input: &str) -> IResult<&str, SaveNameInfo> {
     let (input, backup) = parse_suffix.parse(input)?;
 
     Ok((
-        input, 
+        input,
         SaveNameInfo {
             tag: user_tag.to_owned(),
-            version: ver.map(|x| x.to_owned()),
+            version: ver.map(parse_version_tag_saturating),
             backup_id: backup.map(|x| x.to_owned()),
             internal_tag: internal_tag.map(|x| x.to_owned()),
         }
     ))
 }
 
+/// Converts a version tag recognized by [`parse_version`] into a [`Version`],
+/// saturating any component that overflows `u64` to `u64::MAX` instead of
+/// failing.
+///
+/// `digit1` has no magnitude bound, so a syntactically valid version can
+/// still overflow; `parse`'s `IResult` signature has no room for a
+/// crate-level error to report that, so it saturates. [`parse_checked`]
+/// surfaces the same case as a real [`SaveNameParseError::MalformedVersion`].
+fn parse_version_tag_saturating(version_tag: &str) -> Version {
+    let mut components = Vec::new();
+    if !version_tag.is_empty() {
+        for part in version_tag.split('.') {
+            components.push(part.parse().unwrap_or(u64::MAX));
+        }
+    }
+    Version::from_parts(components, version_tag.to_owned())
+}
+
+/// Parses `input` into a [`SaveNameInfo`], surfacing a crate-owned,
+/// nom-free error describing which stage of the grammar failed.
+///
+/// This backs `SaveNameInfo::from_str`; prefer `input.parse::<SaveNameInfo>()`
+/// over calling this directly.
+pub fn parse_checked(input: &str) -> Result<SaveNameInfo, SaveNameParseError> {
+
+    let original = input;
+    let offset_of = |rest: &str| original.len() - rest.len();
+
+    // 1. internal tag: optional, but once opened with `__` it must close.
+    let (input, internal_tag) = if input.starts_with("__") {
+        parse_tag_internal(input)
+            .map(|(rest, tag)| (rest, Some(tag)))
+            .map_err(|_| SaveNameParseError::UnterminatedInternalTag {
+                substring: input.to_owned(),
+                offset: offset_of(input),
+            })?
+    } else {
+        (input, None)
+    };
+
+    // 2. user tag. The grammar folds the tag, version and suffix together so
+    // it can look ahead for where the suffix starts, so a failure here means
+    // either the `user` prefix itself is missing, or no valid suffix could
+    // be found anywhere in the remainder.
+    let (input, user_tag) = parse_user_tag(input).map_err(|_| {
+        if input.starts_with("user") {
+            SaveNameParseError::InvalidSuffix {
+                substring: input.to_owned(),
+                offset: offset_of(input),
+            }
+        } else {
+            SaveNameParseError::MissingUserPrefix {
+                substring: input.to_owned(),
+                offset: offset_of(input),
+            }
+        }
+    })?;
+
+    // 3. version: re-parsed from the remainder so a version component that
+    // overflows `u64` gets its own error instead of being saturated as in
+    // `parse`. Non-numeric version text never reaches this stage: the user
+    // tag stage has already folded it into the tag. `digit1` has no magnitude bound, so a syntactically valid version can
+    // still overflow `u64`; `Version::from_str` is what actually catches that.
+    let (input, ver) = if input.starts_with('_') {
+        // `parse_version` cannot fail here: its only fallible stage is
+        // `tag("_")`, already guaranteed by the `starts_with('_')` check
+        // above, and `separated_list0` accepts zero elements, so it always
+        // succeeds -- there is no syntax failure left for it to report.
+        let (rest, version_tag) = parse_version(input)
+            .expect("tag(\"_\") already checked, and separated_list0 never fails");
+        let version = version_tag.parse::<Version>().map_err(|_| SaveNameParseError::MalformedVersion {
+            substring: version_tag.to_owned(),
+            // `version_tag` starts right after the `_`, not at `input`, so
+            // the offset has to be measured back from `rest` rather than
+            // reusing the pre-`parse_version` `input` (which would point one
+            // byte too early, at the `_`).
+            offset: offset_of(rest) - version_tag.len(),
+        })?;
+        (rest, Some(version))
+    } else {
+        (input, None)
+    };
+
+    // 4. suffix. `parse_suffix` enforces `eof` internally, so any leftover
+    // input is already rejected above as `InvalidSuffix`; nothing can ever
+    // remain here to report as trailing input.
+    let (_, backup) = parse_suffix(input).map_err(|_| SaveNameParseError::InvalidSuffix {
+        substring: input.to_owned(),
+        offset: offset_of(input),
+    })?;
+
+    Ok(SaveNameInfo {
+        tag: user_tag.to_owned(),
+        version: ver,
+        backup_id: backup.map(|x| x.to_owned()),
+        internal_tag: internal_tag.map(|x| x.to_owned()),
+    })
+}
+
 
 #[test]
 fn test_parse() {
@@ -178,4 +289,71 @@ fn test_parser_user_tag() {
     assert_eq!(parse_user_tag("usera-b_c__d.e_1.0.28891.dat.bak123"), Ok(("_1.0.28891.dat.bak123", "a-b_c__d.e"))); // with version + backup id
 
     assert_eq!(parse_user_tag("user1.dat.dat"), Ok((".dat", "1.dat"))); // an extreme case, `1.dat` as user tag.
+}
+
+#[test]
+fn test_parse_checked() {
+
+    // successful cases agree with the nom-based `parse`.
+    for input in [
+        "user1.dat",
+        "user2_1.0.28891.dat",
+        "user2.dat.bak123",
+        "__pin__useraaa_bbb-ccc.ddd_1.0.28891.dat.bak123",
+    ] {
+        assert_eq!(parse_checked(input).unwrap(), parse(input).unwrap().1);
+    }
+
+    // unterminated internal tag.
+    assert_eq!(
+        parse_checked("__pinuser1.dat"),
+        Err(SaveNameParseError::UnterminatedInternalTag {
+            substring: "__pinuser1.dat".to_owned(),
+            offset: 0,
+        })
+    );
+
+    // missing `user` prefix.
+    assert_eq!(
+        parse_checked("player1.dat"),
+        Err(SaveNameParseError::MissingUserPrefix {
+            substring: "player1.dat".to_owned(),
+            offset: 0,
+        })
+    );
+
+    // no recognizable `.dat`/`.bak` suffix anywhere in the name.
+    assert_eq!(
+        parse_checked("user1.sav"),
+        Err(SaveNameParseError::InvalidSuffix {
+            substring: "user1.sav".to_owned(),
+            offset: 0,
+        })
+    );
+
+    // a version component that overflows `u64` is reported, not panicked on.
+    // `offset` must point at `substring` itself (right after the `_`), not
+    // at the `_` that precedes it.
+    let overflowing = "user1_999999999999999999999999999999.dat";
+    assert_eq!(
+        parse_checked(overflowing),
+        Err(SaveNameParseError::MalformedVersion {
+            substring: "999999999999999999999999999999".to_owned(),
+            offset: 6,
+        })
+    );
+    assert_eq!(
+        &overflowing[6..6 + "999999999999999999999999999999".len()],
+        "999999999999999999999999999999"
+    );
+}
+
+#[test]
+fn test_parse_does_not_panic_on_overflowing_version() {
+    // regression test: `digit1` has no magnitude bound, so `parse` must
+    // saturate an overflowing component (`parse_version_tag_saturating`)
+    // rather than panic.
+    let (rest, info) = parse("user1_999999999999999999999999999999.dat").unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(info.version.unwrap().components(), &[u64::MAX]);
 }
\ No newline at end of file
@@ -1,23 +1,69 @@
 use std::fmt;
+use std::str::FromStr;
 
+use crate::error::{SaveNameParseError, SaveNameValidationError};
+use crate::version::Version;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaveNameInfo {
     pub tag: String,
-    pub version: Option<String>,
+    pub version: Option<Version>,
     pub backup_id: Option<String>,
     pub internal_tag: Option<String>,
 }
 
 impl SaveNameInfo {
-  pub fn new(tag: &str, version: Option<&str>, backup: Option<&str>, internal_tag: Option<&str>) -> Self {
-    SaveNameInfo { 
-      tag: tag.to_owned(), 
-      version: version.map(|x| x.to_owned()), 
-      backup_id: backup.map(|x| x.to_owned()), 
+  pub fn new(tag: &str, version: Option<Version>, backup: Option<&str>, internal_tag: Option<&str>) -> Self {
+    SaveNameInfo {
+      tag: tag.to_owned(),
+      version,
+      backup_id: backup.map(|x| x.to_owned()),
       internal_tag: internal_tag.map(|x| x.to_owned())
     }
   }
+
+  /// Builds a `SaveNameInfo`, rejecting a `tag` containing `.dat`, an
+  /// `internal_tag` containing `__` and a non-numeric `backup_id`. The last
+  /// two break the `Display`/`FromStr` round trip; the `.dat` check is
+  /// stricter than the parser needs (see [`Self::round_trips`] for an
+  /// empirical, rather than syntactic, check of the round trip).
+  pub fn try_new(
+    tag: &str,
+    version: Option<Version>,
+    backup: Option<&str>,
+    internal_tag: Option<&str>,
+  ) -> Result<Self, SaveNameValidationError> {
+    if tag.contains(".dat") {
+      return Err(SaveNameValidationError::TagContainsSuffixMarker { tag: tag.to_owned() });
+    }
+
+    if let Some(internal_tag) = internal_tag {
+      if internal_tag.contains("__") {
+        return Err(SaveNameValidationError::InternalTagContainsDelimiter {
+          internal_tag: internal_tag.to_owned(),
+        });
+      }
+    }
+
+    if let Some(backup) = backup {
+      if !backup.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(SaveNameValidationError::BackupIdNotNumeric { backup_id: backup.to_owned() });
+      }
+    }
+
+    Ok(SaveNameInfo::new(tag, version, backup, internal_tag))
+  }
+
+  /// Checks empirically whether this value survives a `Display` → `FromStr`
+  /// round trip. Unlike [`Self::try_new`]'s syntactic checks, this catches
+  /// any ambiguity the grammar admits, not just the documented ones.
+  pub fn round_trips(&self) -> bool {
+    match self.to_string().parse::<SaveNameInfo>() {
+      Ok(parsed) => parsed == *self,
+      Err(_) => false,
+    }
+  }
 }
 
 impl fmt::Display for SaveNameInfo {
@@ -28,10 +74,10 @@ impl fmt::Display for SaveNameInfo {
         // Example:
         //  - With version tag: user2_1.0.29242.dat 
         //  - Without version tag: user2.dat 
-        let ver_str = 
-            &self.version.clone().map_or("".to_owned(), 
-            |x: String| format!("_{}", x)
-            
+        let ver_str =
+            &self.version.clone().map_or("".to_owned(),
+            |x: Version| format!("_{}", x)
+
         );
 
         // Suffix and extension tag, if have some backup tag, add special
@@ -56,6 +102,19 @@ impl fmt::Display for SaveNameInfo {
     }
 }
 
+impl FromStr for SaveNameInfo {
+    type Err = SaveNameParseError;
+
+    /// Parses a save file name, e.g. `user2_1.0.28891.dat.bak3`.
+    ///
+    /// Unlike [`crate::parser::parse`], this never leaks nom's error types
+    /// or borrowed lifetimes; errors identify which stage of the grammar
+    /// failed.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        crate::parser::parse_checked(input)
+    }
+}
+
 
 #[test]
 fn test_info_to_name() {
@@ -72,7 +131,7 @@ fn test_info_to_name() {
     
     let case2 = SaveNameInfo {
       tag: "4".to_owned(),
-      version: Some("1.0.28650".to_owned()),
+      version: Some("1.0.28650".parse().unwrap()),
       ..case1.clone()
     };
 
@@ -116,3 +175,67 @@ fn test_info_to_name() {
   assert_eq!(case7.to_string(), "__pin__user4_1.0.28650.dat.bak13", "testing: with internal tag");
 
 }
+
+#[test]
+fn test_from_str() {
+
+    let parsed: SaveNameInfo = "user4_1.0.28650.dat.bak13".parse().unwrap();
+    assert_eq!(parsed.tag, "4");
+    assert_eq!(parsed.version.as_ref().map(Version::to_string).as_deref(), Some("1.0.28650"));
+    assert_eq!(parsed.backup_id.as_deref(), Some("13"));
+
+    let err = "player1.dat".parse::<SaveNameInfo>().unwrap_err();
+    assert_eq!(
+        err,
+        SaveNameParseError::MissingUserPrefix {
+            substring: "player1.dat".to_owned(),
+            offset: 0,
+        }
+    );
+}
+
+#[test]
+fn test_try_new_rejects_unrepresentable_fields() {
+
+    assert_eq!(
+        SaveNameInfo::try_new("1.dat", None, None, None),
+        Err(SaveNameValidationError::TagContainsSuffixMarker { tag: "1.dat".to_owned() })
+    );
+
+    assert_eq!(
+        SaveNameInfo::try_new("1", None, None, Some("a__b")),
+        Err(SaveNameValidationError::InternalTagContainsDelimiter { internal_tag: "a__b".to_owned() })
+    );
+
+    assert_eq!(
+        SaveNameInfo::try_new("1", None, Some("12a"), None),
+        Err(SaveNameValidationError::BackupIdNotNumeric { backup_id: "12a".to_owned() })
+    );
+
+    assert!(SaveNameInfo::try_new("1", None, Some("13"), Some("pin")).is_ok());
+}
+
+#[test]
+fn test_round_trips() {
+
+    let good = SaveNameInfo::try_new("4", Some("1.0.28650".parse().unwrap()), Some("13"), Some("pin")).unwrap();
+    assert!(good.round_trips());
+
+    // `try_new` only rejects the documented unsafe patterns; a tag that
+    // merely looks like a version tag still fools the parser on re-parse.
+    let sneaky = SaveNameInfo::try_new("a_30", None, None, None).unwrap();
+    assert!(!sneaky.round_trips());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trips_through_display() {
+
+    let filename = "__pin__user4_1.0.28650.dat.bak13";
+    let parsed: SaveNameInfo = filename.parse().unwrap();
+
+    let json = serde_json::to_string(&parsed).unwrap();
+    let restored: SaveNameInfo = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.to_string(), filename);
+}
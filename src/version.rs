@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed save version, e.g. `1.0.28891` (HKSS) or `1.2.3.28891` (legacy HK).
+///
+/// Components are compared numerically and component-wise, with the
+/// shorter of two versions padded with trailing zeros, so `1.0` and
+/// `1.0.0` compare equal while `1.2.28891` sorts newer than `1.0.28891`.
+/// The original dotted text is kept alongside the parsed components so
+/// `Display` reproduces it exactly, including any leading zeros.
+#[derive(Debug, Clone)]
+pub struct Version {
+    components: Vec<u64>,
+    original: String,
+}
+
+impl Version {
+    /// Builds a version from numeric components, e.g. `vec![1, 0, 28891]`.
+    pub fn new(components: Vec<u64>) -> Self {
+        let original = components
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(".");
+
+        Version { components, original }
+    }
+
+    /// The dot-separated numeric components, e.g. `[1, 0, 28891]`.
+    pub fn components(&self) -> &[u64] {
+        &self.components
+    }
+
+    /// Builds a version from components already split out of `original`,
+    /// preserving `original`'s exact text (e.g. leading zeros) for `Display`.
+    ///
+    /// Used internally by parsers that need to decide how to handle a
+    /// component that doesn't fit in `u64` without going through
+    /// [`FromStr`], which rejects it.
+    pub(crate) fn from_parts(components: Vec<u64>, original: String) -> Self {
+        Version { components, original }
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.components.len().max(other.components.len());
+
+        for i in 0..len {
+            let lhs = self.components.get(i).copied().unwrap_or(0);
+            let rhs = other.components.get(i).copied().unwrap_or(0);
+
+            match lhs.cmp(&rhs) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.original)
+    }
+}
+
+// `Version` keeps private fields to protect the components/original
+// invariant, so it gets a hand-written impl (as its dotted text) rather
+// than `#[derive(Serialize, Deserialize)]`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.original)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A version string was not a dot-separated run of components that each
+/// fit in a `u64` (either a component wasn't numeric, or it overflowed).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionParseError {
+    pub input: String,
+}
+
+impl fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not a dot-separated run of u64 components: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for VersionParseError {}
+
+impl FromStr for Version {
+    type Err = VersionParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // An empty string is a degenerate, all-zero version: the grammar
+        // behind `user1_.dat` permits a `_` with no digits after it.
+        let mut components = Vec::new();
+        if !input.is_empty() {
+            for part in input.split('.') {
+                let component: u64 = part
+                    .parse()
+                    .map_err(|_| VersionParseError { input: input.to_owned() })?;
+                components.push(component);
+            }
+        }
+
+        Ok(Version::from_parts(components, input.to_owned()))
+    }
+}
+
+#[test]
+fn test_version_ordering() {
+
+    let v1: Version = "1.0.28891".parse().unwrap();
+    let v2: Version = "1.2.28891".parse().unwrap();
+    let v3: Version = "1.2.28892".parse().unwrap();
+
+    assert!(v1 < v2);
+    assert!(v2 < v3);
+
+    // shorter version is padded with zeros for comparison.
+    let short: Version = "1.0".parse().unwrap();
+    let long: Version = "1.0.0".parse().unwrap();
+    assert_eq!(short, long);
+
+    let shorter_but_newer: Version = "1.1".parse().unwrap();
+    assert!(long < shorter_but_newer);
+}
+
+#[test]
+fn test_version_display_round_trips_original_text() {
+
+    // leading zeros and unusual component counts are preserved verbatim.
+    for input in ["1.0.28891", "1.2.3.28891", "01.2", "2"] {
+        let v: Version = input.parse().unwrap();
+        assert_eq!(v.to_string(), input);
+    }
+}
+
+#[test]
+fn test_version_parse_error() {
+    assert_eq!(
+        "1.a.2".parse::<Version>(),
+        Err(VersionParseError { input: "1.a.2".to_owned() })
+    );
+
+    // a syntactically valid (digit-only) component that overflows `u64`.
+    let overflowing = "999999999999999999999999";
+    assert_eq!(
+        overflowing.parse::<Version>(),
+        Err(VersionParseError { input: overflowing.to_owned() })
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_version_serde_round_trips_original_text() {
+
+    for input in ["1.0.28891", "1.2.3.28891", "01.2"] {
+        let v: Version = input.parse().unwrap();
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, format!("{input:?}"));
+
+        let restored: Version = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.to_string(), input);
+    }
+}
+
+#[test]
+fn test_version_empty_is_degenerate_zero() {
+
+    // `_` with no digits after it is permitted by the grammar (`user1_.dat`).
+    let v: Version = "".parse().unwrap();
+    assert_eq!(v, "0".parse().unwrap());
+    assert_eq!(v.to_string(), "");
+}